@@ -11,6 +11,7 @@ use std::ffi::OsStr;
 #[cfg(target_family = "unix")]
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::Mutex;
 
 use git2::StatusEntry;
@@ -44,24 +45,21 @@ impl GitCache {
             .map(|repo| repo.search(index, prefix_lookup))
             .unwrap_or_default()
     }
-}
 
-use std::iter::FromIterator;
-impl FromIterator<PathBuf> for GitCache {
-    fn from_iter<I>(iter: I) -> Self
-    where
-        I: IntoIterator<Item = PathBuf>,
-    {
-        let iter = iter.into_iter();
+    /// Builds a `GitCache` for the given paths, querying each repository’s
+    /// statuses according to `options` rather than libgit2’s defaults.
+    #[must_use]
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>, options: GitOptions) -> Self {
+        let paths = paths.into_iter();
         let mut git = Self {
-            repos: Vec::with_capacity(iter.size_hint().0),
+            repos: Vec::with_capacity(paths.size_hint().0),
             misses: Vec::new(),
         };
 
         if let Ok(path) = env::var("GIT_DIR") {
             // These flags are consistent with how `git` uses GIT_DIR:
             let flags = git2::RepositoryOpenFlags::NO_SEARCH | git2::RepositoryOpenFlags::NO_DOTGIT;
-            match GitRepo::discover(path.into(), flags) {
+            match GitRepo::discover(path.into(), flags, options) {
                 Ok(repo) => {
                     debug!("Opened GIT_DIR repo");
                     git.repos.push(repo);
@@ -72,14 +70,14 @@ impl FromIterator<PathBuf> for GitCache {
             }
         }
 
-        for path in iter {
+        for path in paths {
             if git.misses.contains(&path) {
                 debug!("Skipping {path:?} because it already came back Gitless");
             } else if git.repos.iter().any(|e| e.has_path(&path)) {
                 debug!("Skipping {path:?} because we already queried it");
             } else {
                 let flags = git2::RepositoryOpenFlags::FROM_ENV;
-                match GitRepo::discover(path, flags) {
+                match GitRepo::discover(path, flags, options) {
                     Ok(r) => {
                         if let Some(r2) = git.repos.iter_mut().find(|e| e.has_workdir(&r.workdir)) {
                             debug!(
@@ -104,6 +102,106 @@ impl FromIterator<PathBuf> for GitCache {
     }
 }
 
+use std::iter::FromIterator;
+impl FromIterator<PathBuf> for GitCache {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        Self::new(iter, GitOptions::default())
+    }
+}
+
+/// Options controlling how Git status information is gathered, set from the
+/// command-line and threaded down into every repository that gets queried.
+#[derive(Clone, Copy, Debug)]
+pub struct GitOptions {
+    /// Whether untracked files should be reported at all.
+    pub include_untracked: bool,
+
+    /// Whether untracked directories should be recursed into, rather than
+    /// reported as a single untracked directory entry.
+    pub recurse_untracked_dirs: bool,
+
+    /// Whether files ignored by Git should be reported. Leaving this off
+    /// when `--git-ignore` is in play skips a scan that nobody will see.
+    pub include_ignored: bool,
+
+    /// How deeply to check submodules for changes.
+    pub submodule_ignore: SubmoduleIgnore,
+}
+
+impl Default for GitOptions {
+    /// Matches the behaviour of the `repo.statuses(None)` call this replaced,
+    /// which libgit2 resolves to `GIT_STATUS_OPT_DEFAULTS`: untracked and
+    /// ignored files reported, untracked directories recursed into.
+    fn default() -> Self {
+        Self {
+            include_untracked: true,
+            recurse_untracked_dirs: true,
+            include_ignored: true,
+            submodule_ignore: SubmoduleIgnore::default(),
+        }
+    }
+}
+
+/// How deeply to check submodules for changes when computing statuses,
+/// mirroring the values accepted by `git status --ignore-submodules`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubmoduleIgnore {
+    /// Check submodules fully: untracked content, modified content, and commits.
+    #[default]
+    None,
+
+    /// Ignore untracked files and directories inside submodules.
+    Untracked,
+
+    /// Also ignore modified (tracked) content inside submodules.
+    Dirty,
+
+    /// Ignore submodules entirely.
+    All,
+}
+
+impl SubmoduleIgnore {
+    /// The value this variant is spelled as on the `git status
+    /// --ignore-submodules` command line.
+    fn as_cli_value(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Untracked => "untracked",
+            Self::Dirty => "dirty",
+            Self::All => "all",
+        }
+    }
+
+    /// The equivalent `git2::SubmoduleIgnore` value, for use with
+    /// `Repository::submodule_status`.
+    fn to_git2(self) -> git2::SubmoduleIgnore {
+        match self {
+            Self::None => git2::SubmoduleIgnore::None,
+            Self::Untracked => git2::SubmoduleIgnore::Untracked,
+            Self::Dirty => git2::SubmoduleIgnore::Dirty,
+            Self::All => git2::SubmoduleIgnore::All,
+        }
+    }
+}
+
+impl GitOptions {
+    /// Builds the equivalent `git2::StatusOptions` for this configuration.
+    /// Of the submodule levels, only `SubmoduleIgnore::All` has an effect
+    /// here (it excludes submodules); `Untracked` and `Dirty` are not applied.
+    fn to_status_options(self) -> git2::StatusOptions {
+        let mut options = git2::StatusOptions::new();
+        options
+            .include_untracked(self.include_untracked)
+            .recurse_untracked_dirs(self.recurse_untracked_dirs)
+            .include_ignored(self.include_ignored)
+            .exclude_submodules(self.submodule_ignore == SubmoduleIgnore::All);
+        options
+    }
+}
+
 /// A **Git repository** is one we’ve discovered somewhere on the filesystem.
 pub struct GitRepo {
     /// The queryable contents of the repository: either a `git2` repo, or the
@@ -122,6 +220,9 @@ pub struct GitRepo {
     /// Any other paths that were checked only to result in this same
     /// repository.
     extra_paths: Vec<PathBuf>,
+
+    /// The status-gathering options this repository was discovered with.
+    options: GitOptions,
 }
 
 /// A repository’s queried state.
@@ -129,22 +230,39 @@ enum GitContents {
     /// All the interesting Git stuff goes through this.
     Before { repo: git2::Repository },
 
-    /// Temporary value used in `repo_to_statuses` so we can move the
-    /// repository out of the `Before` variant.
+    /// Temporary value used in `search` so we can move the repository out of
+    /// the `Before`/`After` variant.
     Processing,
 
-    /// The data we’ve extracted from the repository, but only after we’ve
-    /// actually done so.
-    After { statuses: Git },
+    /// The data we’ve extracted from the repository, after we’ve actually
+    /// done so. The `git2::Repository` handle is kept around (rather than
+    /// dropped) so an out-of-scope path can trigger a follow-up scan without
+    /// reopening the repository.
+    After { repo: git2::Repository, statuses: Git },
+}
+
+impl GitContents {
+    /// Extracts the `git2::Repository` handle, along with whatever scan
+    /// roots have already been covered (empty if this is the first query).
+    fn into_repo(self) -> (git2::Repository, Vec<PathBuf>) {
+        match self {
+            Self::Before { repo } => (repo, Vec::new()),
+            Self::After { repo, statuses } => (repo, statuses.covered),
+            Self::Processing => unreachable!("Tried to extract a non-Repository"),
+        }
+    }
 }
 
 impl GitRepo {
     /// Searches through this repository for a path (to a file or directory,
     /// depending on the prefix-lookup flag) and returns its Git status.
     ///
-    /// Actually querying the `git2` repository for the mapping of paths to
-    /// Git statuses is only done once, and gets cached so we don’t need to
-    /// re-query the entire repository the times after that.
+    /// Querying the `git2` repository for the mapping of paths to Git
+    /// statuses is scoped to a pathspec built from the paths this repo was
+    /// asked about, so listing one subdirectory of a huge repo doesn’t pay
+    /// for a whole-working-tree diff. If a later lookup falls outside the
+    /// scan’s covered roots, that’s not treated as “not modified” — we
+    /// re-query with an expanded pathspec instead.
     ///
     /// The temporary `Processing` enum variant is used after the `git2`
     /// repository is moved out, but before the results have been moved in!
@@ -153,19 +271,52 @@ impl GitRepo {
         use std::mem::replace;
 
         let mut contents = self.contents.lock().unwrap();
-        if let GitContents::After { ref statuses } = *contents {
-            debug!("Git repo {:?} has been found in cache", &self.workdir);
-            return statuses.status(index, prefix_lookup);
+        if let GitContents::After { ref statuses, .. } = *contents {
+            if statuses.covers(index) {
+                debug!("Git repo {:?} has been found in cache", &self.workdir);
+                return statuses.status(index, prefix_lookup);
+            }
+            debug!(
+                "{index:?} falls outside the cached scan of {:?}; re-querying",
+                &self.workdir
+            );
+        } else {
+            debug!("Querying Git repo {:?} for the first time", &self.workdir);
         }
 
-        debug!("Querying Git repo {:?} for the first time", &self.workdir);
-        let repo = replace(&mut *contents, GitContents::Processing).inner_repo();
-        let statuses = repo_to_statuses(&repo, &self.workdir);
+        let (repo, covered_so_far) = replace(&mut *contents, GitContents::Processing).into_repo();
+        let roots = self.scan_roots(index, &covered_so_far);
+        let statuses = repo_to_statuses(&repo, &self.workdir, self.options, &roots);
         let result = statuses.status(index, prefix_lookup);
-        let _processing = replace(&mut *contents, GitContents::After { statuses });
+        let _processing = replace(&mut *contents, GitContents::After { repo, statuses });
         result
     }
 
+    /// The pathspec roots, as given, to scope the next scan to: every root
+    /// already covered, plus whatever’s needed to additionally cover `index`.
+    /// An empty result means “scan the whole working tree” — used whenever
+    /// any of those roots is the workdir itself, since a pathspec can’t be
+    /// scoped any narrower than that (and stripping the workdir against
+    /// itself would produce a bogus empty-string pathspec entry).
+    fn scan_roots(&self, index: &Path, covered_so_far: &[PathBuf]) -> Vec<PathBuf> {
+        let mut roots: Vec<PathBuf> = std::iter::once(self.original_path.clone())
+            .chain(self.extra_paths.iter().cloned())
+            .chain(covered_so_far.iter().cloned())
+            .collect();
+
+        if !roots.iter().any(|root| index.starts_with(root)) {
+            roots.push(index.to_path_buf());
+        }
+
+        if roots.contains(&self.workdir) {
+            return Vec::new();
+        }
+
+        roots.sort();
+        roots.dedup();
+        roots
+    }
+
     /// Whether this repository has the given working directory.
     fn has_workdir(&self, path: &Path) -> bool {
         self.workdir == path
@@ -180,7 +331,11 @@ impl GitRepo {
     /// Open a Git repository. Depending on the flags, the path is either
     /// the repository's "gitdir" (or a "gitlink" to the gitdir), or the
     /// path is the start of a rootwards search for the repository.
-    fn discover(path: PathBuf, flags: git2::RepositoryOpenFlags) -> Result<Self, PathBuf> {
+    fn discover(
+        path: PathBuf,
+        flags: git2::RepositoryOpenFlags,
+        options: GitOptions,
+    ) -> Result<Self, PathBuf> {
         info!("Opening Git repository for {path:?} ({flags:?})");
         let unused: [&OsStr; 0] = [];
         let repo = match git2::Repository::open_ext(&path, flags, unused) {
@@ -199,6 +354,7 @@ impl GitRepo {
                 workdir,
                 original_path: path,
                 extra_paths: Vec::new(),
+                options,
             })
         } else {
             warn!("Repository has no workdir?");
@@ -207,28 +363,160 @@ impl GitRepo {
     }
 }
 
-impl GitContents {
-    /// Assumes that the repository hasn’t been queried, and extracts it
-    /// (consuming the value) if it has. This is needed because the entire
-    /// enum variant gets replaced when a repo is queried (see above).
-    fn inner_repo(self) -> git2::Repository {
-        if let Self::Before { repo } = self {
-            repo
-        } else {
-            unreachable!("Tried to extract a non-Repository")
+/// Queries a repository’s statuses, returning the mapping of files to their
+/// Git status.
+/// We will have already used the working directory at this point, so it gets
+/// passed in rather than deriving it from the `Repository` again. `roots` is
+/// the set of paths, as given, to scope the scan to (empty means the whole
+/// working tree), which also becomes the resulting `Git::covered` set.
+fn repo_to_statuses(
+    repo: &git2::Repository,
+    workdir: &Path,
+    options: GitOptions,
+    roots: &[PathBuf],
+) -> Git {
+    info!("Getting Git statuses for repo with workdir {workdir:?} (roots: {roots:?})");
+
+    let pathspecs = relative_pathspecs(workdir, roots);
+
+    let mut statuses = match git_cli_statuses(workdir, options, &pathspecs) {
+        Some(statuses) => statuses,
+        None => {
+            debug!("Falling back to libgit2 for Git statuses");
+            libgit2_statuses(repo, workdir, options, &pathspecs)
         }
+    };
+
+    // We manually add the `.git` at the root of the repo as ignored, since it is in practice.
+    // Also we want to avoid `eza --tree --all --git-ignore` to display files inside `.git`.
+    statuses.push((workdir.join(".git"), git2::Status::IGNORED));
+
+    let branch = branch_info(repo);
+    let submodules = submodule_statuses(repo, workdir, options.submodule_ignore);
+
+    Git {
+        statuses,
+        branch,
+        covered: roots.to_vec(),
+        submodules,
     }
 }
 
-/// Iterates through a repository’s statuses, consuming it and returning the
-/// mapping of files to their Git status.
-/// We will have already used the working directory at this point, so it gets
-/// passed in rather than deriving it from the `Repository` again.
-fn repo_to_statuses(repo: &git2::Repository, workdir: &Path) -> Git {
+/// What kind of change a submodule has, distinct from the regular file
+/// statuses since “a submodule is modified” can mean several different
+/// things: a different commit checked out, a dirty worktree inside it,
+/// untracked content inside it, or it simply not being checked out at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmoduleKind {
+    /// The submodule has a different commit checked out than the one
+    /// recorded in the superproject’s index.
+    NewCommits,
+
+    /// The submodule’s worktree has tracked changes.
+    Dirty,
+
+    /// The submodule’s worktree has untracked content.
+    Untracked,
+
+    /// The submodule hasn’t been initialised/cloned at all.
+    Uninitialized,
+}
+
+/// Looks up every submodule in the repository and, for those that aren’t
+/// clean, what’s going on with them. Errors looking up an individual
+/// submodule are logged and that submodule is skipped, rather than failing
+/// the whole scan.
+fn submodule_statuses(
+    repo: &git2::Repository,
+    workdir: &Path,
+    ignore: SubmoduleIgnore,
+) -> Vec<(PathBuf, SubmoduleKind)> {
     let mut statuses = Vec::new();
 
-    info!("Getting Git statuses for repo with workdir {workdir:?}");
-    match repo.statuses(None) {
+    let submodules = match repo.submodules() {
+        Ok(submodules) => submodules,
+        Err(e) => {
+            error!("Error looking up submodules: {e:?}");
+            return statuses;
+        }
+    };
+
+    for submodule in &submodules {
+        let Some(name) = submodule.name() else {
+            continue;
+        };
+
+        let status = match repo.submodule_status(name, ignore.to_git2()) {
+            Ok(status) => status,
+            Err(e) => {
+                error!("Error looking up status for submodule {name:?}: {e:?}");
+                continue;
+            }
+        };
+
+        if let Some(kind) = submodule_kind(status) {
+            statuses.push((workdir.join(submodule.path()), kind));
+        }
+    }
+
+    statuses
+}
+
+/// Picks the most relevant `SubmoduleKind` for a submodule’s raw
+/// `git2::SubmoduleStatus` flags, or `None` if it’s clean. An uninitialised
+/// submodule takes priority, since there’s nothing checked out to be dirty;
+/// after that, a changed commit is the most significant, then worktree
+/// dirtiness, then untracked content.
+fn submodule_kind(status: git2::SubmoduleStatus) -> Option<SubmoduleKind> {
+    use git2::SubmoduleStatus as S;
+
+    if status.intersects(S::WD_UNINITIALIZED) {
+        Some(SubmoduleKind::Uninitialized)
+    } else if status.intersects(S::WD_ADDED | S::WD_DELETED | S::WD_MODIFIED | S::INDEX_MODIFIED) {
+        Some(SubmoduleKind::NewCommits)
+    } else if status.intersects(S::WD_WD_MODIFIED | S::WD_INDEX_MODIFIED) {
+        Some(SubmoduleKind::Dirty)
+    } else if status.intersects(S::WD_UNTRACKED) {
+        Some(SubmoduleKind::Untracked)
+    } else {
+        None
+    }
+}
+
+/// Converts scan roots (absolute, or relative to the current directory) into
+/// pathspecs relative to the working directory, the form both `git status`
+/// and `StatusOptions::pathspec` expect. A root that resolves to the working
+/// directory itself makes the result empty, meaning the whole working tree.
+/// A root that doesn’t resolve to somewhere under `workdir` is dropped; if
+/// none are left, the result is empty too.
+fn relative_pathspecs(workdir: &Path, roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut pathspecs = Vec::new();
+    for root in roots {
+        match reorient(root).strip_prefix(workdir) {
+            Ok(relative) if relative.as_os_str().is_empty() => return Vec::new(),
+            Ok(relative) => pathspecs.push(relative.to_path_buf()),
+            Err(_) => {}
+        }
+    }
+    pathspecs
+}
+
+/// Queries the status of a repository using libgit2. This is the original,
+/// reliable way of getting statuses, but it can take a very long time on
+/// large working trees (see the comment below).
+fn libgit2_statuses(
+    repo: &git2::Repository,
+    workdir: &Path,
+    options: GitOptions,
+    pathspecs: &[PathBuf],
+) -> Vec<(PathBuf, git2::Status)> {
+    let mut statuses = Vec::new();
+    let mut status_options = options.to_status_options();
+    for pathspec in pathspecs {
+        status_options.pathspec(pathspec);
+    }
+
+    match repo.statuses(Some(&mut status_options)) {
         Ok(es) => {
             for e in es.iter() {
                 if let Some(p) = get_path_from_status_entry(&e) {
@@ -236,16 +524,186 @@ fn repo_to_statuses(repo: &git2::Repository, workdir: &Path) -> Git {
                     statuses.push(elem);
                 }
             }
-            // We manually add the `.git` at the root of the repo as ignored, since it is in practice.
-            // Also we want to avoid `eza --tree --all --git-ignore` to display files inside `.git`.
-            statuses.push((workdir.join(".git"), git2::Status::IGNORED));
         }
         Err(e) => {
             error!("Error looking up Git statuses: {e:?}");
         }
     }
 
-    Git { statuses }
+    statuses
+}
+
+/// Queries the status of a repository by shelling out to the system `git`
+/// binary rather than going through libgit2. This is often considerably
+/// faster on large working trees, since `git` itself doesn’t pay the cost of
+/// libgit2’s status diffing machinery. Returns `None` (so the caller can fall
+/// back to libgit2) if `git` isn’t on the `PATH`, or if it exits unsuccessfully.
+fn git_cli_statuses(
+    workdir: &Path,
+    options: GitOptions,
+    pathspecs: &[PathBuf],
+) -> Option<Vec<(PathBuf, git2::Status)>> {
+    let untracked_files = match (options.include_untracked, options.recurse_untracked_dirs) {
+        (false, _) => "no",
+        (true, false) => "normal",
+        (true, true) => "all",
+    };
+
+    let mut command = Command::new("git");
+    command.arg("-C").arg(workdir).args([
+        "status",
+        "--porcelain=v2",
+        "-z",
+        &format!("--untracked-files={untracked_files}"),
+        &format!(
+            "--ignore-submodules={}",
+            options.submodule_ignore.as_cli_value()
+        ),
+    ]);
+
+    if options.include_ignored {
+        command.arg("--ignored");
+    }
+
+    if !pathspecs.is_empty() {
+        command.arg("--");
+        command.args(pathspecs);
+    }
+
+    let output = command.output().ok()?;
+
+    if !output.status.success() {
+        warn!(
+            "`git status` exited with {:?}, falling back to libgit2",
+            output.status.code()
+        );
+        return None;
+    }
+
+    Some(parse_porcelain_v2(&output.stdout, workdir))
+}
+
+/// Parses the NUL-separated output of `git status --porcelain=v2 -z` into the
+/// same `(PathBuf, git2::Status)` pairs that libgit2’s status iterator
+/// produces, so both backends can feed the same `Git` container.
+///
+/// See `git-status(1)` for the full format; in short, each record starts with
+/// a one-character kind (`1` ordinary change, `2` rename/copy, `u` unmerged,
+/// `?` untracked, `!` ignored) followed by fields specific to that kind, and
+/// records are NUL-terminated instead of newline-terminated when `-z` is
+/// passed. Rename/copy records are followed by an extra NUL-terminated field
+/// holding the path’s original location.
+fn parse_porcelain_v2(stdout: &[u8], workdir: &Path) -> Vec<(PathBuf, git2::Status)> {
+    let mut statuses = Vec::new();
+    let mut fields = stdout.split(|&b| b == 0).filter(|f| !f.is_empty());
+
+    while let Some(record) = fields.next() {
+        let Some((kind, rest)) = split_once(record, b' ') else {
+            continue;
+        };
+
+        match kind {
+            b"1" => {
+                // 1 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <path>
+                let Some((xy, rest)) = split_once(rest, b' ') else {
+                    continue;
+                };
+                let Some(path) = nth_field_onward(rest, 6) else {
+                    continue;
+                };
+                statuses.push((workdir.join(path_from_bytes(path)), status_from_xy(xy)));
+            }
+            b"2" => {
+                // 2 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <X><score> <path>\0<origPath>\0
+                let Some((xy, rest)) = split_once(rest, b' ') else {
+                    continue;
+                };
+                let Some(path) = nth_field_onward(rest, 7) else {
+                    continue;
+                };
+                statuses.push((workdir.join(path_from_bytes(path)), status_from_xy(xy)));
+                // The original path is consumed as the next NUL-separated
+                // field; we don’t need it, since we key off the new path.
+                fields.next();
+            }
+            b"u" => {
+                // u <XY> <sub> <m1> <m2> <m3> <mW> <h1> <h2> <h3> <path>
+                // `rest` still starts with `<XY>` here (unlike the `1`/`2`
+                // arms, it hasn't been split off), so 9 fields need
+                // skipping to reach `<path>`, not 8.
+                let Some(path) = nth_field_onward(rest, 9) else {
+                    continue;
+                };
+                statuses.push((workdir.join(path_from_bytes(path)), git2::Status::CONFLICTED));
+            }
+            b"?" => {
+                statuses.push((workdir.join(path_from_bytes(rest)), git2::Status::WT_NEW));
+            }
+            b"!" => {
+                statuses.push((workdir.join(path_from_bytes(rest)), git2::Status::IGNORED));
+            }
+            _ => {
+                debug!("Ignoring unrecognised `git status` record kind {kind:?}");
+            }
+        }
+    }
+
+    statuses
+}
+
+/// Splits `haystack` on the first occurrence of `sep`, returning the part
+/// before and the (possibly empty) remainder after it.
+fn split_once(haystack: &[u8], sep: u8) -> Option<(&[u8], &[u8])> {
+    let pos = haystack.iter().position(|&b| b == sep)?;
+    Some((&haystack[..pos], &haystack[pos + 1..]))
+}
+
+/// Skips `n` space-separated fields and returns whatever follows, which is
+/// taken to be the (final, possibly space-containing) path field.
+fn nth_field_onward(haystack: &[u8], n: usize) -> Option<&[u8]> {
+    let mut rest = haystack;
+    for _ in 0..n {
+        (_, rest) = split_once(rest, b' ')?;
+    }
+    Some(rest)
+}
+
+/// Maps a porcelain v2 `XY` status pair to the equivalent `git2::Status`
+/// flags, combining the index (`X`) and worktree (`Y`) sides.
+fn status_from_xy(xy: &[u8]) -> git2::Status {
+    let mut status = git2::Status::empty();
+    if xy.len() != 2 {
+        return status;
+    }
+
+    status |= match xy[0] {
+        b'M' => git2::Status::INDEX_MODIFIED,
+        b'A' => git2::Status::INDEX_NEW,
+        b'D' => git2::Status::INDEX_DELETED,
+        b'R' => git2::Status::INDEX_RENAMED,
+        b'T' => git2::Status::INDEX_TYPECHANGE,
+        _ => git2::Status::empty(),
+    };
+
+    status |= match xy[1] {
+        b'M' => git2::Status::WT_MODIFIED,
+        b'D' => git2::Status::WT_DELETED,
+        b'R' => git2::Status::WT_RENAMED,
+        b'T' => git2::Status::WT_TYPECHANGE,
+        _ => git2::Status::empty(),
+    };
+
+    status
+}
+
+/// Converts the raw path bytes from `git`’s output into a `PathBuf`. On Unix
+/// this is the same as `get_path_from_status_entry` does for libgit2 paths;
+/// elsewhere, non-UTF-8 bytes are replaced rather than the path being skipped.
+fn path_from_bytes(bytes: &[u8]) -> PathBuf {
+    #[cfg(target_family = "unix")]
+    return PathBuf::from(OsStr::from_bytes(bytes));
+    #[cfg(not(target_family = "unix"))]
+    return PathBuf::from(String::from_utf8_lossy(bytes).into_owned());
 }
 
 #[allow(clippy::unnecessary_wraps)]
@@ -272,9 +730,37 @@ fn get_path_from_status_entry(e: &StatusEntry<'_>) -> Option<PathBuf> {
 /// Container of Git statuses for all the files in this folder’s Git repository.
 struct Git {
     statuses: Vec<(PathBuf, git2::Status)>,
+
+    /// The repository’s current branch, ahead/behind count, and last commit
+    /// time, computed once alongside `statuses` and handed back with every
+    /// lookup so a Git column can render it without a second query.
+    branch: GitBranchInfo,
+
+    /// The pathspec roots, as given, this scan was scoped to. Empty means the
+    /// whole working tree was scanned, so every path is covered.
+    covered: Vec<PathBuf>,
+
+    /// Submodules found in the repository that aren’t clean, keyed by their
+    /// absolute path.
+    submodules: Vec<(PathBuf, SubmoduleKind)>,
 }
 
 impl Git {
+    /// Whether `path` falls within one of this scan’s covered roots (or the
+    /// whole working tree was scanned). A `false` here means the statuses
+    /// we’re holding can’t speak for `path`, and a re-query is needed.
+    fn covers(&self, path: &Path) -> bool {
+        self.covered.is_empty() || self.covered.iter().any(|root| path.starts_with(root))
+    }
+
+    /// The submodule kind at exactly this path, if it is a submodule.
+    fn submodule_kind(&self, path: &Path) -> Option<SubmoduleKind> {
+        self.submodules
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, kind)| *kind)
+    }
+
     /// Get either the file or directory status for the given path.
     /// “Prefix lookup” means that it should report an aggregate status of all
     /// paths starting with the given prefix (in other words, a directory).
@@ -292,6 +778,16 @@ impl Git {
     fn file_status(&self, file: &Path) -> f::Git {
         let path = reorient(file);
 
+        if let Some(kind) = self.submodule_kind(&path) {
+            return f::Git {
+                staged: f::GitStatus::NotModified,
+                unstaged: f::GitStatus::Submodule(kind),
+                branch: self.branch.name.clone(),
+                ahead_behind: self.branch.ahead_behind,
+                commit_time: self.branch.commit_time,
+            };
+        }
+
         let s = self
             .statuses
             .iter()
@@ -306,7 +802,13 @@ impl Git {
 
         let staged = index_status(s);
         let unstaged = working_tree_status(s);
-        f::Git { staged, unstaged }
+        f::Git {
+            staged,
+            unstaged,
+            branch: self.branch.name.clone(),
+            ahead_behind: self.branch.ahead_behind,
+            commit_time: self.branch.commit_time,
+        }
     }
 
     /// Get the combined, user-facing status of a directory.
@@ -314,9 +816,22 @@ impl Git {
     /// modified if any file under it has the status modified), except for
     /// ignored status which applies to files under (for example, a directory
     /// is considered ignored if one of its parent directories is ignored).
+    /// A submodule is a leaf: its own state is reported directly, rather
+    /// than recursing into (nonexistent, as far as this repo’s statuses are
+    /// concerned) entries beneath it.
     fn dir_status(&self, dir: &Path) -> f::Git {
         let path = reorient(dir);
 
+        if let Some(kind) = self.submodule_kind(&path) {
+            return f::Git {
+                staged: f::GitStatus::NotModified,
+                unstaged: f::GitStatus::Submodule(kind),
+                branch: self.branch.name.clone(),
+                ahead_behind: self.branch.ahead_behind,
+                commit_time: self.branch.commit_time,
+            };
+        }
+
         let s = self
             .statuses
             .iter()
@@ -331,7 +846,13 @@ impl Git {
 
         let staged = index_status(s);
         let unstaged = working_tree_status(s);
-        f::Git { staged, unstaged }
+        f::Git {
+            staged,
+            unstaged,
+            branch: self.branch.name.clone(),
+            ahead_behind: self.branch.ahead_behind,
+            commit_time: self.branch.commit_time,
+        }
     }
 }
 
@@ -393,48 +914,105 @@ fn index_status(status: git2::Status) -> f::GitStatus {
     };
 }
 
-fn current_branch(repo: &git2::Repository) -> Option<String> {
+/// The current branch’s name, its ahead/behind count relative to its
+/// upstream, and the committer time of its tip commit.
+#[derive(Clone, Debug, Default)]
+struct GitBranchInfo {
+    name: Option<String>,
+    ahead_behind: Option<(usize, usize)>,
+    commit_time: Option<i64>,
+}
+
+/// Looks up everything we can cheaply say about HEAD: its shorthand name,
+/// how far ahead/behind its upstream it is, and when its tip commit landed.
+/// Degrades gracefully — an unborn or detached HEAD just means no ahead/behind
+/// and possibly no name; no configured upstream just means no ahead/behind.
+fn branch_info(repo: &git2::Repository) -> GitBranchInfo {
     let head = match repo.head() {
-        Ok(head) => Some(head),
+        Ok(head) => head,
         Err(ref e)
             if e.code() == git2::ErrorCode::UnbornBranch
                 || e.code() == git2::ErrorCode::NotFound =>
         {
-            return None;
+            return GitBranchInfo {
+                name: unborn_branch_name(repo),
+                ..GitBranchInfo::default()
+            };
         }
         Err(e) => {
             error!("Error looking up Git branch: {e:?}");
-            return None;
+            return GitBranchInfo::default();
         }
     };
 
-    head.and_then(|h| h.shorthand().map(std::string::ToString::to_string))
+    let name = head.shorthand().map(std::string::ToString::to_string);
+    let commit_time = head.peel_to_commit().ok().map(|c| c.time().seconds());
+    let ahead_behind = head_ahead_behind(repo, &head);
+
+    GitBranchInfo {
+        name,
+        ahead_behind,
+        commit_time,
+    }
+}
+
+/// On an unborn branch (a freshly-initialised repo with no commits yet),
+/// `repo.head()` errors out entirely, but the branch name it would resolve
+/// to once a commit lands is still readable straight off the symbolic
+/// `HEAD` reference.
+fn unborn_branch_name(repo: &git2::Repository) -> Option<String> {
+    let head_ref = repo.find_reference("HEAD").ok()?;
+    let target = head_ref.symbolic_target()?;
+    Some(target.strip_prefix("refs/heads/").unwrap_or(target).to_string())
+}
+
+/// How many commits HEAD is ahead of and behind its upstream branch, or
+/// `None` if HEAD is detached or has no configured upstream.
+fn head_ahead_behind(
+    repo: &git2::Repository,
+    head: &git2::Reference<'_>,
+) -> Option<(usize, usize)> {
+    let head_name = head.name()?;
+    let head_oid = head.target()?;
+    let upstream_name = repo.branch_upstream_name(head_name).ok()?;
+    let upstream_oid = repo.refname_to_id(upstream_name.as_str()?).ok()?;
+    repo.graph_ahead_behind(head_oid, upstream_oid).ok()
 }
 
 impl f::SubdirGitRepo {
     #[must_use]
-    pub fn from_path(dir: &Path, status: bool) -> Self {
+    pub fn from_path(dir: &Path, status: bool, options: GitOptions) -> Self {
         let path = &reorient(dir);
 
         if let Ok(repo) = git2::Repository::open(path) {
-            let branch = current_branch(&repo);
+            let branch_info = branch_info(&repo);
+            let branch = branch_info.name;
+            let ahead_behind = branch_info.ahead_behind;
+            let commit_time = branch_info.commit_time;
             if !status {
                 return Self {
                     status: None,
                     branch,
+                    ahead_behind,
+                    commit_time,
                 };
             }
-            match repo.statuses(None) {
+            let mut status_options = options.to_status_options();
+            match repo.statuses(Some(&mut status_options)) {
                 Ok(es) => {
                     if es.iter().any(|s| s.status() != git2::Status::IGNORED) {
                         return Self {
                             status: Some(f::SubdirGitRepoStatus::GitDirty),
                             branch,
+                            ahead_behind,
+                            commit_time,
                         };
                     }
                     return Self {
                         status: Some(f::SubdirGitRepoStatus::GitClean),
                         branch,
+                        ahead_behind,
+                        commit_time,
                     };
                 }
                 Err(e) => {
@@ -449,6 +1027,82 @@ impl f::SubdirGitRepo {
                 None
             },
             branch: None,
+            ahead_behind: None,
+            commit_time: None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(record: &str) -> Vec<(PathBuf, git2::Status)> {
+        parse_porcelain_v2(record.as_bytes(), Path::new("/repo"))
+    }
+
+    #[test]
+    fn parses_ordinary_record() {
+        let statuses = parse("1 M. N... 100644 100644 100644 0000000000000000000000000000000000000000 0000000000000000000000000000000000000000 a.txt\0");
+        assert_eq!(
+            statuses,
+            vec![(PathBuf::from("/repo/a.txt"), git2::Status::INDEX_MODIFIED)]
+        );
+    }
+
+    #[test]
+    fn parses_rename_record_and_skips_orig_path() {
+        let stdout = b"2 R. N... 100644 100644 100644 0000000000000000000000000000000000000000 0000000000000000000000000000000000000000 R100 new.txt\0old.txt\0? untracked.txt\0";
+        let statuses = parse_porcelain_v2(stdout, Path::new("/repo"));
+        assert_eq!(
+            statuses,
+            vec![
+                (PathBuf::from("/repo/new.txt"), git2::Status::INDEX_RENAMED),
+                (PathBuf::from("/repo/untracked.txt"), git2::Status::WT_NEW),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_unmerged_record_as_conflicted() {
+        let statuses = parse("u UU N... 100644 100644 100644 100644 0000000000000000000000000000000000000000 0000000000000000000000000000000000000000 0000000000000000000000000000000000000000 conflicted.txt\0");
+        assert_eq!(
+            statuses,
+            vec![(PathBuf::from("/repo/conflicted.txt"), git2::Status::CONFLICTED)]
+        );
+    }
+
+    #[test]
+    fn parses_untracked_record() {
+        let statuses = parse("? untracked.txt\0");
+        assert_eq!(
+            statuses,
+            vec![(PathBuf::from("/repo/untracked.txt"), git2::Status::WT_NEW)]
+        );
+    }
+
+    #[test]
+    fn parses_ignored_record() {
+        let statuses = parse("! ignored.txt\0");
+        assert_eq!(
+            statuses,
+            vec![(PathBuf::from("/repo/ignored.txt"), git2::Status::IGNORED)]
+        );
+    }
+
+    #[test]
+    fn status_from_xy_combines_index_and_worktree_sides() {
+        assert_eq!(
+            status_from_xy(b"MD"),
+            git2::Status::INDEX_MODIFIED | git2::Status::WT_DELETED
+        );
+        assert_eq!(status_from_xy(b"A."), git2::Status::INDEX_NEW);
+        assert_eq!(status_from_xy(b".M"), git2::Status::WT_MODIFIED);
+    }
+
+    #[test]
+    fn status_from_xy_rejects_malformed_pairs() {
+        assert_eq!(status_from_xy(b"M"), git2::Status::empty());
+        assert_eq!(status_from_xy(b""), git2::Status::empty());
+    }
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2024 Christina Sørensen
+// SPDX-License-Identifier: EUPL-1.2
+//
+// SPDX-FileCopyrightText: 2023-2024 Christina Sørensen, eza contributors
+// SPDX-FileCopyrightText: 2014 Benjamin Sago
+// SPDX-License-Identifier: MIT
+//! The user-facing values rendered into output columns.
+
+/// The Git status of a file or directory: its staged and unstaged state,
+/// plus the name of the branch the containing repository is on, how far
+/// ahead/behind that branch is from its upstream, and the committer time
+/// of its tip commit — so an output column can render e.g. `main ↑2↓1 (3d ago)`.
+#[derive(Clone, Debug, Default)]
+pub struct Git {
+    pub staged: GitStatus,
+    pub unstaged: GitStatus,
+    pub branch: Option<String>,
+    pub ahead_behind: Option<(usize, usize)>,
+    pub commit_time: Option<i64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GitStatus {
+    #[default]
+    NotModified,
+    New,
+    Modified,
+    Deleted,
+    Renamed,
+    TypeChange,
+    Ignored,
+    Conflicted,
+    Submodule(crate::fs::feature::git::SubmoduleKind),
+}
+
+/// The Git status of a subdirectory passed directly on the command line:
+/// its branch, how far ahead/behind that branch is from its upstream, the
+/// committer time of its tip commit, and a coarse dirty/clean/no-repo state.
+#[derive(Clone, Debug, Default)]
+pub struct SubdirGitRepo {
+    pub status: Option<SubdirGitRepoStatus>,
+    pub branch: Option<String>,
+    pub ahead_behind: Option<(usize, usize)>,
+    pub commit_time: Option<i64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubdirGitRepoStatus {
+    NoRepo,
+    GitClean,
+    GitDirty,
+}